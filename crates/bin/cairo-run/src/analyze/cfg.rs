@@ -0,0 +1,59 @@
+//! Builds a control-flow graph over a Sierra program's statements.
+
+use std::collections::HashMap;
+
+use cairo_lang_sierra::program::{BranchTarget, GenStatement, Program, StatementIdx};
+
+/// The control-flow graph of a compiled Sierra program.
+///
+/// This is the per-function, intra-procedural CFG: edges come only from each `Invocation`'s own
+/// branch targets (including fallthrough). A `function_call` invocation's branch target is its
+/// fallthrough statement in the *caller*, not a jump into the callee - it does not add an edge to
+/// the callee's entry point. `Return` statements have no successors here. The whole-program call
+/// graph (caller function -> callee function) is built separately, in `printer::print_call_graph_dot`.
+pub struct ControlFlowGraph {
+    pub successors: HashMap<StatementIdx, Vec<StatementIdx>>,
+    pub predecessors: HashMap<StatementIdx, Vec<StatementIdx>>,
+}
+
+impl ControlFlowGraph {
+    /// Builds the CFG from `program`'s statements.
+    pub fn build(program: &Program) -> Self {
+        let mut successors: HashMap<StatementIdx, Vec<StatementIdx>> = HashMap::new();
+        for (idx, statement) in program.statements.iter().enumerate() {
+            let idx = StatementIdx(idx);
+            let targets = match statement {
+                GenStatement::Return(_) => vec![],
+                GenStatement::Invocation(invocation) => invocation
+                    .branches
+                    .iter()
+                    .map(|branch| match branch.target {
+                        BranchTarget::Fallthrough => idx.next(),
+                        BranchTarget::Statement(target) => target,
+                    })
+                    .collect(),
+            };
+            successors.insert(idx, targets);
+        }
+        let mut predecessors: HashMap<StatementIdx, Vec<StatementIdx>> = HashMap::new();
+        for (&from, tos) in &successors {
+            for &to in tos {
+                predecessors.entry(to).or_default().push(from);
+            }
+        }
+        Self { successors, predecessors }
+    }
+}
+
+/// Returns the statement indices belonging to the function starting at `entry_point`: everything
+/// up to (but excluding) the next function's entry point, or the end of the program.
+pub fn statements_of_function(program: &Program, entry_point: StatementIdx) -> Vec<StatementIdx> {
+    let mut entry_points: Vec<usize> = program.funcs.iter().map(|f| f.entry_point.0).collect();
+    entry_points.sort_unstable();
+    let end = entry_points
+        .iter()
+        .find(|&&idx| idx > entry_point.0)
+        .copied()
+        .unwrap_or(program.statements.len());
+    (entry_point.0..end).map(StatementIdx).collect()
+}
@@ -0,0 +1,225 @@
+//! Pluggable Sierra lint detectors, registered in [`registry`].
+
+use std::collections::HashSet;
+
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc;
+use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::{GenStatement, Invocation, Program, StatementIdx};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+
+use super::cfg::{statements_of_function, ControlFlowGraph};
+
+/// A single detector finding, reported with the Sierra statement it concerns.
+pub struct Finding {
+    pub detector: &'static str,
+    pub statement_idx: StatementIdx,
+    pub message: String,
+}
+
+/// A Sierra-level lint: inspects the program and its CFG and reports zero or more findings.
+pub trait Detector {
+    /// A short, stable name identifying this detector in findings and CLI output.
+    fn name(&self) -> &'static str;
+    /// Runs the detector, returning any findings.
+    fn run(
+        &self,
+        program: &Program,
+        cfg: &ControlFlowGraph,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Vec<Finding>;
+}
+
+/// Resolves `invocation`'s libfunc and reports whether it's a `function_call`.
+fn is_function_call(invocation: &Invocation, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> bool {
+    matches!(registry.get_libfunc(&invocation.libfunc_id), Ok(CoreConcreteLibfunc::FunctionCall(_)))
+}
+
+/// Resolves `invocation`'s libfunc and reports whether it's a gas withdrawal.
+fn is_withdraw_gas(invocation: &Invocation, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> bool {
+    matches!(
+        registry.get_libfunc(&invocation.libfunc_id),
+        Ok(CoreConcreteLibfunc::Gas(
+            GasConcreteLibfunc::WithdrawGas(_) | GasConcreteLibfunc::BuiltinWithdrawGas(_)
+        ))
+    )
+}
+
+/// Resolves `invocation`'s libfunc and reports whether it's a `store_temp`.
+fn is_store_temp(invocation: &Invocation, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> bool {
+    matches!(
+        registry.get_libfunc(&invocation.libfunc_id),
+        Ok(CoreConcreteLibfunc::Mem(MemConcreteLibfunc::StoreTemp(_)))
+    )
+}
+
+/// Resolves `invocation`'s libfunc and reports whether it's a `drop`.
+fn is_drop(invocation: &Invocation, registry: &ProgramRegistry<CoreType, CoreLibfunc>) -> bool {
+    matches!(registry.get_libfunc(&invocation.libfunc_id), Ok(CoreConcreteLibfunc::Drop(_)))
+}
+
+/// The detectors run by `cairo-run analyze`.
+pub fn registry() -> Vec<Box<dyn Detector>> {
+    vec![
+        Box::new(UnreachableStatements),
+        Box::new(UnconsumedCallResults),
+        Box::new(UnboundedLoops),
+        Box::new(DeadStoreTempDrop),
+    ]
+}
+
+/// Flags statements with no incoming CFG edge that also aren't a function entry point.
+struct UnreachableStatements;
+impl Detector for UnreachableStatements {
+    fn name(&self) -> &'static str {
+        "unreachable-statement"
+    }
+    fn run(
+        &self,
+        program: &Program,
+        cfg: &ControlFlowGraph,
+        _registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Vec<Finding> {
+        let entry_points: HashSet<StatementIdx> = program.funcs.iter().map(|f| f.entry_point).collect();
+        (0..program.statements.len())
+            .map(StatementIdx)
+            .filter(|idx| idx.0 != 0 && !entry_points.contains(idx))
+            .filter(|idx| cfg.predecessors.get(idx).map(Vec::is_empty).unwrap_or(true))
+            .map(|idx| Finding {
+                detector: self.name(),
+                statement_idx: idx,
+                message: "statement is never reached".to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Flags `function_call` results that are never used as an input to any statement of the same
+/// function, i.e. values a call returned that were then silently discarded.
+struct UnconsumedCallResults;
+impl Detector for UnconsumedCallResults {
+    fn name(&self) -> &'static str {
+        "unconsumed-return-value"
+    }
+    fn run(
+        &self,
+        program: &Program,
+        _cfg: &ControlFlowGraph,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Vec<Finding> {
+        // Sierra `VarId`s are reused across functions (entry-point params are simply 0..n in each
+        // function), so `used` has to be scoped per function - otherwise a dropped call result in
+        // one function is masked as "used" the moment any other function reuses its id.
+        let mut findings = Vec::new();
+        for func in &program.funcs {
+            let statement_idxs = statements_of_function(program, func.entry_point);
+            let mut used: HashSet<VarId> = HashSet::new();
+            for &idx in &statement_idxs {
+                match &program.statements[idx.0] {
+                    GenStatement::Invocation(invocation) => {
+                        used.extend(invocation.args.iter().cloned())
+                    }
+                    GenStatement::Return(vars) => used.extend(vars.iter().cloned()),
+                }
+            }
+            for &idx in &statement_idxs {
+                let GenStatement::Invocation(invocation) = &program.statements[idx.0] else { continue };
+                if !is_function_call(invocation, registry) {
+                    continue;
+                }
+                for branch in &invocation.branches {
+                    for result in &branch.results {
+                        if !used.contains(result) {
+                            findings.push(Finding {
+                                detector: self.name(),
+                                statement_idx: idx,
+                                message: format!("result `{result}` of this call is never used"),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        findings
+    }
+}
+
+/// Flags back-edges (a branch target at or before its source) whose loop body withdraws no gas,
+/// meaning the loop could run forever without being charged for it.
+struct UnboundedLoops;
+impl Detector for UnboundedLoops {
+    fn name(&self) -> &'static str {
+        "unbounded-loop"
+    }
+    fn run(
+        &self,
+        program: &Program,
+        cfg: &ControlFlowGraph,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for (&from, tos) in &cfg.successors {
+            for &to in tos {
+                if to.0 > from.0 {
+                    continue;
+                }
+                let withdraws_gas = (to.0..=from.0).any(|i| {
+                    matches!(
+                        program.statements.get(i),
+                        Some(GenStatement::Invocation(invocation))
+                            if is_withdraw_gas(invocation, registry)
+                    )
+                });
+                if !withdraws_gas {
+                    findings.push(Finding {
+                        detector: self.name(),
+                        statement_idx: from,
+                        message: format!(
+                            "back-edge to {to:?} has no gas withdrawal in its loop body"
+                        ),
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+/// Flags a `store_temp` whose sole result is immediately consumed only by a `drop`, i.e. a value
+/// that was materialized and then thrown away without ever being used.
+struct DeadStoreTempDrop;
+impl Detector for DeadStoreTempDrop {
+    fn name(&self) -> &'static str {
+        "dead-store-temp-drop"
+    }
+    fn run(
+        &self,
+        program: &Program,
+        _cfg: &ControlFlowGraph,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for idx in 0..program.statements.len().saturating_sub(1) {
+            let Some(GenStatement::Invocation(store)) = program.statements.get(idx) else { continue };
+            if !is_store_temp(store, registry) {
+                continue;
+            }
+            let Some(GenStatement::Invocation(next)) = program.statements.get(idx + 1) else {
+                continue;
+            };
+            if !is_drop(next, registry) {
+                continue;
+            }
+            let Some(stored) = store.branches[0].results.first() else { continue };
+            if next.args.len() == 1 && &next.args[0] == stored {
+                findings.push(Finding {
+                    detector: self.name(),
+                    statement_idx: StatementIdx(idx),
+                    message: "store_temp result is immediately dropped without use".to_string(),
+                });
+            }
+        }
+        findings
+    }
+}
@@ -0,0 +1,85 @@
+//! Static analysis over a compiled Sierra program: `cairo-run analyze`.
+//!
+//! Builds the program's control-flow graph and runs a registry of detectors over it - e.g.
+//! unreachable statements, call results that are never consumed, loops with no gas withdrawal on
+//! their back-edge, and dead `store_temp`/`drop` pairs - or, with `--printer`, dumps the CFG or
+//! call graph instead.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::{check_compiler_path, setup_project};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
+use clap::{Parser, ValueEnum};
+
+mod cfg;
+mod detectors;
+mod printer;
+
+/// Arguments for `cairo-run analyze`.
+#[derive(Parser, Debug)]
+pub struct AnalyzeArgs {
+    /// The file to analyze.
+    path: PathBuf,
+    /// Whether path is a single file.
+    #[arg(short, long)]
+    single_file: bool,
+    /// Prints a graph instead of running detectors.
+    #[arg(long, value_enum)]
+    printer: Option<Printer>,
+}
+
+/// The graph `--printer` dumps, in DOT format.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Printer {
+    /// The per-function control-flow graph.
+    Cfg,
+    /// The whole-program call graph.
+    CallGraph,
+}
+
+/// Runs `cairo-run analyze`.
+pub fn analyze(args: AnalyzeArgs) -> anyhow::Result<()> {
+    check_compiler_path(args.single_file, &args.path)?;
+
+    let db = &mut RootDatabase::builder().detect_corelib().build()?;
+    let main_crate_ids = setup_project(db, &args.path)?;
+    if DiagnosticsReporter::stderr().check(db) {
+        anyhow::bail!("failed to compile: {}", args.path.display());
+    }
+
+    let sierra_program = db
+        .get_sierra_program(main_crate_ids)
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let replacer = DebugReplacer { db };
+    let program = replacer.apply(&sierra_program);
+
+    let cfg = cfg::ControlFlowGraph::build(&program);
+    let registry = cairo_lang_sierra::program_registry::ProgramRegistry::new(&program)
+        .with_context(|| "Failed setting up the program registry.")?;
+
+    if let Some(printer) = args.printer {
+        match printer {
+            Printer::Cfg => printer::print_cfg_dot(&program, &cfg),
+            Printer::CallGraph => printer::print_call_graph_dot(&program, &registry),
+        }
+        return Ok(());
+    }
+
+    let findings: Vec<detectors::Finding> = detectors::registry()
+        .iter()
+        .flat_map(|detector| detector.run(&program, &cfg, &registry))
+        .collect();
+    if findings.is_empty() {
+        println!("No findings.");
+    }
+    for finding in &findings {
+        println!("[{}] {:?}: {}", finding.detector, finding.statement_idx, finding.message);
+    }
+    Ok(())
+}
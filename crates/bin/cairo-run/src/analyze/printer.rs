@@ -0,0 +1,66 @@
+//! DOT output for `--printer cfg` / `--printer call-graph`.
+
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::program::{GenStatement, Program};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+
+use super::cfg::{self, ControlFlowGraph};
+
+fn sanitize(name: &str) -> String {
+    name.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_path_separators() {
+        assert_eq!(sanitize("my_crate::my_module::foo"), "my_crate__my_module__foo");
+    }
+
+    #[test]
+    fn leaves_alphanumeric_untouched() {
+        assert_eq!(sanitize("Foo123"), "Foo123");
+    }
+}
+
+/// Prints the control-flow graph of every function in `program` as a single DOT digraph, one
+/// subgraph cluster per function.
+pub fn print_cfg_dot(program: &Program, cfg: &ControlFlowGraph) {
+    println!("digraph cfg {{");
+    for func in &program.funcs {
+        println!("  subgraph cluster_{} {{", sanitize(&func.id.to_string()));
+        println!("    label=\"{}\";", func.id);
+        for idx in cfg::statements_of_function(program, func.entry_point) {
+            println!("    s{} [label=\"{idx:?}\"];", idx.0);
+            for succ in cfg.successors.get(&idx).into_iter().flatten() {
+                println!("    s{} -> s{};", idx.0, succ.0);
+            }
+        }
+        println!("  }}");
+    }
+    println!("}}");
+}
+
+/// Prints the whole-program call graph (caller function -> callee function) as DOT.
+pub fn print_call_graph_dot(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) {
+    println!("digraph call_graph {{");
+    for func in &program.funcs {
+        for idx in cfg::statements_of_function(program, func.entry_point) {
+            let Some(GenStatement::Invocation(invocation)) = program.statements.get(idx.0) else {
+                continue;
+            };
+            let Ok(CoreConcreteLibfunc::FunctionCall(call)) =
+                registry.get_libfunc(&invocation.libfunc_id)
+            else {
+                continue;
+            };
+            println!("  \"{}\" -> \"{}\";", func.id, call.function.id);
+        }
+    }
+    println!("}}");
+}
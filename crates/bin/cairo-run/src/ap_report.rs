@@ -0,0 +1,51 @@
+//! Prints the `--ap-report` table/JSON: a per-function view of `calc_ap_changes`'s predictions.
+
+use cairo_lang_sierra::program::Program;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// The format `--ap-report` prints in.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ApReportFormat {
+    Table,
+    Json,
+}
+
+#[derive(Serialize)]
+struct ReportRow {
+    function: String,
+    ap_change: Option<usize>,
+    locals_size: usize,
+    has_unknown_branch: bool,
+}
+
+/// Computes and prints the AP-usage report for `program` in the requested `format`.
+pub fn print(program: &Program, format: ApReportFormat) -> anyhow::Result<()> {
+    let info = cairo_lang_sierra_ap_change::calc_ap_changes(program)?;
+    let rows: Vec<ReportRow> = cairo_lang_sierra_ap_change::ap_report::build_reports(program, &info)?
+        .into_iter()
+        .map(|report| ReportRow {
+            function: report.function_id.to_string(),
+            ap_change: report.ap_change,
+            locals_size: report.locals_size,
+            has_unknown_branch: report.has_unknown_branch,
+        })
+        .collect();
+
+    match format {
+        ApReportFormat::Json => println!("{}", serde_json::to_string_pretty(&rows)?),
+        ApReportFormat::Table => {
+            println!("{:<40} {:>10} {:>12} {:>8}", "function", "ap_change", "locals_size", "unknown");
+            for row in &rows {
+                println!(
+                    "{:<40} {:>10} {:>12} {:>8}",
+                    row.function,
+                    row.ap_change.map(|v| v.to_string()).unwrap_or_else(|| "?".to_string()),
+                    row.locals_size,
+                    if row.has_unknown_branch { "yes" } else { "no" }
+                );
+            }
+        }
+    }
+    Ok(())
+}
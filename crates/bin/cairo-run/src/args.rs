@@ -0,0 +1,115 @@
+//! Parses `--args` values (felt252 literals or short strings) into `Felt252`s, and validates them
+//! against a function's signature before running it.
+
+use anyhow::{bail, Context};
+use cairo_felt::Felt252;
+use cairo_lang_sierra::program::Function;
+use num_bigint::BigInt;
+
+/// Concrete type names that the runner supplies itself (gas, range-check, ...) rather than the
+/// caller - these don't count against the number of `--args` the user must provide.
+pub(crate) const IMPLICIT_PARAM_TYPES: &[&str] =
+    &["RangeCheck", "GasBuiltin", "SegmentArena", "Bitwise", "Pedersen", "Poseidon", "System"];
+
+/// Parses a single `--args` value: a decimal/hex felt252 literal, or a short string in single
+/// quotes (e.g. `'hello'`).
+fn parse_value(raw: &str) -> anyhow::Result<Felt252> {
+    if let Some(short_string) = raw.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        if short_string.len() > 31 {
+            bail!("short string `{short_string}` is longer than 31 bytes");
+        }
+        let mut value = BigInt::from(0);
+        for byte in short_string.bytes() {
+            value = value * 256 + byte;
+        }
+        return Ok(Felt252::from(value));
+    }
+    if let Some(hex) = raw.strip_prefix("0x") {
+        let value = BigInt::parse_bytes(hex.as_bytes(), 16)
+            .with_context(|| format!("`{raw}` is not a valid hex felt252 literal"))?;
+        return Ok(Felt252::from(value));
+    }
+    let value =
+        raw.parse::<BigInt>().with_context(|| format!("`{raw}` is not a valid felt252 literal"))?;
+    Ok(Felt252::from(value))
+}
+
+/// Parses every `--args` value and validates both the resulting count and each value's type
+/// against `function`'s explicit (non-builtin) parameters, erroring clearly on mismatch.
+///
+/// `--args` only ever produces felt252 literals, so any explicit parameter that isn't itself a
+/// bare `felt252` (e.g. an `Array<felt252>` or a struct) can't be supplied this way; flag it
+/// instead of silently passing a felt252 value where the callee expects a different memory
+/// layout, which would corrupt memory at runtime.
+pub fn parse_and_validate(raw_args: &[String], function: &Function) -> anyhow::Result<Vec<Felt252>> {
+    let values =
+        raw_args.iter().map(|raw| parse_value(raw)).collect::<anyhow::Result<Vec<_>>>()?;
+
+    let explicit_params: Vec<_> = function
+        .signature
+        .param_types
+        .iter()
+        .filter(|ty| {
+            !IMPLICIT_PARAM_TYPES
+                .iter()
+                .any(|implicit| ty.debug_name.as_deref() == Some(implicit))
+        })
+        .collect();
+    if values.len() != explicit_params.len() {
+        bail!(
+            "function `{}` expects {} argument(s), but {} were provided via --args",
+            function.id,
+            explicit_params.len(),
+            values.len()
+        );
+    }
+    for (position, param_type) in explicit_params.iter().enumerate() {
+        if param_type.debug_name.as_deref() != Some("felt252") {
+            bail!(
+                "function `{}`'s argument #{} has type `{}`, which --args can't supply (only \
+                 felt252 literals and short strings are supported)",
+                function.id,
+                position + 1,
+                param_type.debug_name.as_deref().unwrap_or("<unknown>")
+            );
+        }
+    }
+    Ok(values)
+}
+
+/// Converts a `Felt252` into the `BigInt` representation the Sierra interpreter's `Value::Felt252`
+/// works with.
+pub fn to_bigint(felt: &Felt252) -> BigInt {
+    felt.to_bigint()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_decimal_literal() {
+        assert_eq!(parse_value("42").unwrap(), Felt252::from(42));
+    }
+
+    #[test]
+    fn parses_hex_literal() {
+        assert_eq!(parse_value("0x2a").unwrap(), Felt252::from(42));
+    }
+
+    #[test]
+    fn parses_short_string() {
+        assert_eq!(parse_value("'hi'").unwrap(), Felt252::from(BigInt::from(0x6869_u32)));
+    }
+
+    #[test]
+    fn rejects_overlong_short_string() {
+        let raw = format!("'{}'", "a".repeat(32));
+        assert!(parse_value(&raw).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_literal() {
+        assert!(parse_value("not-a-number").is_err());
+    }
+}
@@ -0,0 +1,105 @@
+//! Reconstructs a source-mapped call stack for a panicking run, turning the raw felt payload
+//! `RunResultValue::Panic` carries into an actionable backtrace. Enabled with `--backtrace`.
+
+use cairo_felt::Felt252;
+use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+
+/// A single frame of a reconstructed backtrace. Frames are ordered from the failing frame first
+/// to the outermost caller last.
+pub struct Frame {
+    /// The name of the enclosing Sierra function, after `DebugReplacer` resolution.
+    pub function_name: String,
+    /// The Cairo source location of the failing statement, when debug info carries one.
+    pub location: Option<String>,
+}
+
+/// Maps a relocated trace `pc` to the `StatementIdx` whose code starts at or before it.
+///
+/// `pc` is a relocated address (cairo-vm reserves address 0, so the program segment is relocated
+/// starting at 1), while `code_offset` is a plain 0-indexed bytecode offset - subtract the
+/// program's base address before comparing them.
+fn statement_at_offset(
+    sierra_statement_info: &[cairo_lang_casm::debug_info::StatementDebugInfo],
+    pc: usize,
+) -> Option<StatementIdx> {
+    let offset = pc.checked_sub(1)?;
+    sierra_statement_info
+        .iter()
+        .enumerate()
+        .filter(|(_, info)| info.code_offset <= offset)
+        .max_by_key(|(_, info)| info.code_offset)
+        .map(|(idx, _)| StatementIdx(idx))
+}
+
+/// Finds the function whose `entry_point` most closely precedes `statement_idx`.
+fn enclosing_function<'a>(
+    program: &'a Program,
+    statement_idx: StatementIdx,
+) -> Option<&'a cairo_lang_sierra::program::GenFunction<StatementIdx>> {
+    program
+        .funcs
+        .iter()
+        .filter(|f| f.entry_point.0 <= statement_idx.0)
+        .max_by_key(|f| f.entry_point.0)
+}
+
+/// Converts a relocated memory cell (a felt known to hold an address) into a plain offset.
+fn as_usize(value: &Felt252) -> anyhow::Result<usize> {
+    value.to_bigint().to_usize().ok_or_else(|| anyhow::anyhow!("address {value} does not fit in a usize"))
+}
+
+/// Walks the `fp` return-address chain recorded in `memory`, starting at the `pc`/`fp` of the last
+/// entry in `trace` - the moment the run panicked - and resolves each frame back to Cairo source.
+/// `sierra_program` must already have its ids resolved through `DebugReplacer` so that function
+/// names are human-readable.
+pub fn reconstruct(
+    runner: &SierraCasmRunner,
+    sierra_program: &Program,
+    memory: &[Option<Felt252>],
+    trace: &[RelocatedTraceEntry],
+) -> anyhow::Result<Vec<Frame>> {
+    let last_entry =
+        trace.last().ok_or_else(|| anyhow::anyhow!("run produced an empty execution trace"))?;
+    let debug_info = &runner.get_casm_program().debug_info.sierra_statement_info;
+    let mut frames = Vec::new();
+    let mut pc = last_entry.pc;
+    let mut fp = last_entry.fp;
+    loop {
+        let statement_idx = statement_at_offset(debug_info, pc)
+            .ok_or_else(|| anyhow::anyhow!("no Sierra statement maps to CASM offset {pc}"))?;
+        let function_name = enclosing_function(sierra_program, statement_idx)
+            .map(|f| f.id.to_string())
+            .unwrap_or_else(|| "<unknown>".to_string());
+        let location = debug_info
+            .get(statement_idx.0)
+            .and_then(|info| info.locations.first())
+            .map(|loc| format!("{loc:?}"));
+        frames.push(Frame { function_name, location });
+
+        // Cairo's calling convention stores the caller's fp at [fp - 2] and the return pc at
+        // [fp - 1]; the outermost frame is reached once the "return" fp stops advancing.
+        let return_pc = memory.get(fp - 1).and_then(|cell| cell.as_ref()).map(as_usize).transpose()?;
+        let return_fp = memory.get(fp - 2).and_then(|cell| cell.as_ref()).map(as_usize).transpose()?;
+        match (return_pc, return_fp) {
+            (Some(return_pc), Some(return_fp)) if return_fp != fp => {
+                pc = return_pc;
+                fp = return_fp;
+            }
+            _ => break,
+        }
+    }
+    Ok(frames)
+}
+
+/// Prints a top-to-bottom backtrace, one line per frame.
+pub fn print(frames: &[Frame]) {
+    println!("Backtrace:");
+    for (depth, frame) in frames.iter().enumerate() {
+        match &frame.location {
+            Some(location) => println!("  #{depth} {} at {location}", frame.function_name),
+            None => println!("  #{depth} {}", frame.function_name),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Artifact emission for `cairo-run`, selected with repeatable `--emit KIND=PATH` flags.
+//!
+//! Replaces the old behavior of unconditionally dumping Sierra/CASM/bytecode to hardcoded paths:
+//! by default nothing is written, and callers opt into exactly the artifacts they need.
+
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::Context;
+use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra::program::Program;
+use serde::Serialize;
+
+/// The artifact a single `--emit` flag produces.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EmitKind {
+    /// The Sierra program, with debug ids resolved.
+    Sierra,
+    /// The Sierra program, with raw (unresolved) ids.
+    SierraNoDebug,
+    /// The lowered CASM program.
+    Casm,
+    /// The assembled CASM bytecode, one felt per line.
+    CasmBytecode,
+    /// A JSON dump of the statement-to-CASM-offset debug info.
+    DebugInfo,
+}
+
+/// One `--emit KIND=PATH` occurrence.
+#[derive(Clone, Debug)]
+pub struct EmitSpec {
+    pub kind: EmitKind,
+    pub path: PathBuf,
+}
+
+impl FromStr for EmitSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(arg: &str) -> anyhow::Result<Self> {
+        let (kind, path) = arg
+            .split_once('=')
+            .with_context(|| format!("invalid --emit value `{arg}`, expected KIND=PATH"))?;
+        let kind = match kind {
+            "sierra" => EmitKind::Sierra,
+            "sierra-no-debug" => EmitKind::SierraNoDebug,
+            "casm" => EmitKind::Casm,
+            "casm-bytecode" => EmitKind::CasmBytecode,
+            "debug-info" => EmitKind::DebugInfo,
+            other => anyhow::bail!(
+                "unknown --emit kind `{other}`, expected one of: sierra, sierra-no-debug, casm, \
+                 casm-bytecode, debug-info"
+            ),
+        };
+        Ok(EmitSpec { kind, path: PathBuf::from(path) })
+    }
+}
+
+/// A JSON-serializable view of `debug_info.sierra_statement_info`, plus enough declaration and
+/// function metadata for downstream tooling to consume the mapping programmatically.
+#[derive(Serialize)]
+struct DebugInfoJson {
+    statement_offsets: Vec<StatementOffsetJson>,
+    type_declarations_count: usize,
+    libfunc_declarations_count: usize,
+    functions: Vec<FunctionEntryJson>,
+}
+
+#[derive(Serialize)]
+struct StatementOffsetJson {
+    statement_idx: usize,
+    code_offset: usize,
+}
+
+#[derive(Serialize)]
+struct FunctionEntryJson {
+    name: String,
+    entry_point: usize,
+}
+
+/// Writes every artifact requested via `--emit`, using `sierra_program_with_debug` (ids already
+/// resolved through `DebugReplacer`) and `sierra_program_no_debug` (the raw compiler output) for
+/// the two Sierra variants, and `runner`'s CASM program for everything else.
+pub fn write_artifacts(
+    specs: &[EmitSpec],
+    sierra_program_with_debug: &Program,
+    sierra_program_no_debug: &Program,
+    runner: &SierraCasmRunner,
+) -> anyhow::Result<()> {
+    for spec in specs {
+        match spec.kind {
+            EmitKind::Sierra => {
+                fs::write(&spec.path, sierra_program_with_debug.to_string())
+                    .with_context(|| format!("failed writing {}", spec.path.display()))?;
+            }
+            EmitKind::SierraNoDebug => {
+                fs::write(&spec.path, sierra_program_no_debug.to_string())
+                    .with_context(|| format!("failed writing {}", spec.path.display()))?;
+            }
+            EmitKind::Casm => {
+                fs::write(&spec.path, runner.get_casm_program().to_string())
+                    .with_context(|| format!("failed writing {}", spec.path.display()))?;
+            }
+            EmitKind::CasmBytecode => {
+                let bytecode: Vec<String> = runner
+                    .get_casm_program()
+                    .instructions
+                    .iter()
+                    .flat_map(|instruction| instruction.assemble().encode())
+                    .map(|felt| felt.to_string())
+                    .collect();
+                fs::write(&spec.path, bytecode.join("\n"))
+                    .with_context(|| format!("failed writing {}", spec.path.display()))?;
+            }
+            EmitKind::DebugInfo => {
+                let debug_info = &runner.get_casm_program().debug_info;
+                let json = DebugInfoJson {
+                    statement_offsets: debug_info
+                        .sierra_statement_info
+                        .iter()
+                        .enumerate()
+                        .map(|(statement_idx, info)| StatementOffsetJson {
+                            statement_idx,
+                            code_offset: info.code_offset,
+                        })
+                        .collect(),
+                    type_declarations_count: sierra_program_with_debug.type_declarations.len(),
+                    libfunc_declarations_count: sierra_program_with_debug.libfunc_declarations.len(),
+                    functions: sierra_program_with_debug
+                        .funcs
+                        .iter()
+                        .map(|f| FunctionEntryJson {
+                            name: f.id.to_string(),
+                            entry_point: f.entry_point.0,
+                        })
+                        .collect(),
+                };
+                let file = fs::File::create(&spec.path)
+                    .with_context(|| format!("failed creating {}", spec.path.display()))?;
+                serde_json::to_writer_pretty(file, &json)
+                    .with_context(|| format!("failed writing {}", spec.path.display()))?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,712 @@
+//! A Sierra-level interpreter, used as an alternative to `SierraCasmRunner` via `--emulate`.
+//!
+//! Unlike the CASM runner, this backend never lowers the program to CASM: it walks the Sierra
+//! statements directly, resolving each invocation's concrete libfunc through a
+//! `ProgramRegistry<CoreType, CoreLibfunc>` and executing its semantics on a small set of tagged
+//! runtime values. This is useful for diagnosing codegen bugs and for running programs on
+//! platforms where CASM execution is unavailable.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::enm::EnumConcreteLibfunc;
+use cairo_lang_sierra::extensions::felt252_dict::{
+    Felt252DictConcreteLibfunc, Felt252DictEntryConcreteLibfunc,
+};
+use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc;
+use cairo_lang_sierra::extensions::int::unsigned::{
+    Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete,
+};
+use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
+use cairo_lang_sierra::extensions::int::{IntConcrete, IntOperator};
+use cairo_lang_sierra::extensions::lib_func::SignatureOnlyConcreteLibfunc;
+use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
+use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
+use cairo_lang_sierra::extensions::structure::StructConcreteLibfunc;
+use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::program::{BranchTarget, GenStatement, Program, StatementIdx};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+use num_bigint::BigInt;
+
+use crate::args::IMPLICIT_PARAM_TYPES;
+
+/// A runtime value produced and consumed while interpreting a Sierra program.
+#[derive(Clone, Debug)]
+pub enum Value {
+    Felt252(BigInt),
+    Uint8(u8),
+    Uint16(u16),
+    Uint32(u32),
+    Uint64(u64),
+    Uint128(u128),
+    Array(Vec<Value>),
+    Struct(Vec<Value>),
+    Enum { variant: usize, value: Box<Value> },
+    Box(Box<Value>),
+    Nullable(Option<Box<Value>>),
+    Felt252Dict(HashMap<BigInt, Value>),
+    /// Opaque builtin that is only ever threaded through, never inspected.
+    RangeCheck,
+    GasBuiltin(i64),
+}
+
+/// The outcome of interpreting a function to completion.
+pub enum InterpreterResultValue {
+    Success(Vec<Value>),
+    Panic(Vec<Value>),
+}
+
+/// The full result of an interpreted run, mirroring `cairo_lang_runner::RunResult`.
+pub struct InterpreterResult {
+    pub value: InterpreterResultValue,
+    pub gas_counter: Option<i64>,
+}
+
+/// An activation record on the interpreter's call stack.
+struct Frame {
+    /// The statement to resume at in the caller once the callee returns.
+    resume_at: StatementIdx,
+    /// The caller's variable bindings, restored when control returns to it.
+    vars: HashMap<VarId, Value>,
+    /// The caller-side variable ids that the callee's return values bind to.
+    result_vars: Vec<VarId>,
+}
+
+/// Error produced while interpreting a Sierra program.
+#[derive(thiserror::Error, Debug)]
+pub enum InterpreterError {
+    #[error("libfunc `{libfunc_id}` at statement {statement_idx:?} is not supported by --emulate")]
+    UnsupportedLibfunc { libfunc_id: String, statement_idx: StatementIdx },
+    #[error("statement {0:?} is out of bounds")]
+    StatementOutOfBounds(StatementIdx),
+    #[error("variable `{0}` was not bound in the current frame")]
+    UnboundVariable(VarId),
+    #[error("out of gas")]
+    OutOfGas,
+}
+
+/// Interprets `program` starting at `entry_point`, passing `args` as the entry function's
+/// parameters, and decrementing `available_gas` by one per executed statement when provided.
+pub fn run(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    entry_point: StatementIdx,
+    args: Vec<Value>,
+    available_gas: Option<usize>,
+) -> anyhow::Result<InterpreterResult> {
+    let mut gas_counter = available_gas.map(|gas| gas as i64);
+    let mut pc = entry_point;
+    let mut vars: HashMap<VarId, Value> = HashMap::new();
+    // The entry function's parameters are bound here, in declaration order, and `args` is
+    // positional over the explicit ones. Implicit builtin
+    // parameters (range-check, gas, ...) aren't part of `args` at all - `args::parse_and_validate`
+    // only ever produces the user's explicit `--args` values - so seed those first and interleave
+    // the explicit args into the remaining (non-builtin) parameter slots.
+    let entry_func = program
+        .funcs
+        .iter()
+        .find(|f| f.entry_point == entry_point)
+        .with_context(|| format!("no function with entry point {entry_point:?}"))?;
+    let mut explicit_args = args.into_iter();
+    for param in &entry_func.params {
+        let value = match param.ty.debug_name.as_deref() {
+            Some("GasBuiltin") => Value::GasBuiltin(available_gas.unwrap_or(0) as i64),
+            Some(name) if IMPLICIT_PARAM_TYPES.contains(&name) => Value::RangeCheck,
+            _ => explicit_args
+                .next()
+                .with_context(|| format!("missing argument for parameter `{}`", param.id))?,
+        };
+        vars.insert(param.id.clone(), value);
+    }
+    let mut stack: Vec<Frame> = Vec::new();
+
+    loop {
+        if let Some(gas) = gas_counter.as_mut() {
+            *gas -= 1;
+            if *gas < 0 {
+                panic!("Out of gas");
+            }
+        }
+        let statement = program
+            .statements
+            .get(pc.0)
+            .ok_or(InterpreterError::StatementOutOfBounds(pc))?;
+        match statement {
+            GenStatement::Return(ret_vars) => {
+                let values = ret_vars
+                    .iter()
+                    .map(|id| take_var(&mut vars, id))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match stack.pop() {
+                    None => {
+                        return Ok(InterpreterResult {
+                            value: InterpreterResultValue::Success(values),
+                            gas_counter,
+                        });
+                    }
+                    Some(frame) => {
+                        vars = frame.vars;
+                        for (id, value) in frame.result_vars.into_iter().zip(values) {
+                            vars.insert(id, value);
+                        }
+                        pc = frame.resume_at;
+                    }
+                }
+            }
+            GenStatement::Invocation(invocation) => {
+                let libfunc = registry
+                    .get_libfunc(&invocation.libfunc_id)
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                let inputs = invocation
+                    .args
+                    .iter()
+                    .map(|id| take_var(&mut vars, id))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match step(libfunc, program, registry, invocation, inputs, &mut stack, &mut vars, pc)
+                {
+                    Ok(StepOutcome::Next(next_pc)) => pc = next_pc,
+                    Ok(StepOutcome::Called { callee_pc }) => pc = callee_pc,
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+}
+
+/// Where control flow should go after executing one statement.
+enum StepOutcome {
+    /// Continue within the current frame at this statement.
+    Next(StatementIdx),
+    /// A new frame was pushed; resume inside the callee.
+    Called { callee_pc: StatementIdx },
+}
+
+/// Executes the semantics of a single invocation, producing outputs bound into `vars` (or a new
+/// frame pushed onto `stack` for function calls) and picking the next statement.
+fn step(
+    libfunc: &CoreConcreteLibfunc,
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    inputs: Vec<Value>,
+    stack: &mut Vec<Frame>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    match libfunc {
+        CoreConcreteLibfunc::FunctionCall(call) => {
+            let callee = program
+                .funcs
+                .iter()
+                .find(|f| f.id == call.function.id)
+                .with_context(|| format!("unknown function {:?}", call.function.id))?;
+            let branch = &invocation.branches[0];
+            let resume_at = fallthrough_target(pc, branch);
+            let mut callee_vars = HashMap::new();
+            for (param, arg) in callee.params.iter().zip(inputs) {
+                callee_vars.insert(param.id.clone(), arg);
+            }
+            let caller_vars = std::mem::replace(vars, callee_vars);
+            stack.push(Frame { resume_at, vars: caller_vars, result_vars: branch.results.clone() });
+            Ok(StepOutcome::Called { callee_pc: callee.entry_point })
+        }
+        CoreConcreteLibfunc::Enum(enum_libfunc) => {
+            exec_enum(enum_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Struct(struct_libfunc) => {
+            exec_struct(struct_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Array(array_libfunc) => {
+            exec_array(array_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Box(box_libfunc) => exec_box(box_libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Felt252(felt_libfunc) => {
+            exec_felt252(felt_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Uint8(libfunc) => exec_uint8(libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Uint16(libfunc) => exec_uint16(libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Uint32(libfunc) => exec_uint32(libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Uint64(libfunc) => exec_uint64(libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Uint128(libfunc) => exec_uint128(libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Gas(gas_libfunc) => exec_gas(gas_libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::Nullable(nullable_libfunc) => {
+            exec_nullable(nullable_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Felt252Dict(dict_libfunc) => {
+            exec_felt252_dict(dict_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Felt252DictEntry(entry_libfunc) => {
+            exec_felt252_dict_entry(entry_libfunc, invocation, inputs, vars, pc)
+        }
+        CoreConcreteLibfunc::Mem(mem_libfunc) => exec_mem(mem_libfunc, invocation, inputs, vars, pc),
+        CoreConcreteLibfunc::BranchAlign(_) => {
+            // Just a marker the real ap-change model uses to keep merging branches' `ap` in sync;
+            // the interpreter has no `ap` register to align.
+            let branch = &invocation.branches[0];
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+        CoreConcreteLibfunc::Drop(_) => {
+            // The dropped value was already removed from `vars` by `take_var`; there's nothing
+            // left to bind.
+            Ok(StepOutcome::Next(single_target(pc, invocation)))
+        }
+        CoreConcreteLibfunc::Dup(_) => exec_dup(invocation, inputs, vars, pc),
+        _ => Err(InterpreterError::UnsupportedLibfunc {
+            libfunc_id: invocation.libfunc_id.to_string(),
+            statement_idx: pc,
+        }
+        .into()),
+    }
+}
+
+fn take_var(vars: &mut HashMap<VarId, Value>, id: &VarId) -> Result<Value, InterpreterError> {
+    vars.remove(id).ok_or_else(|| InterpreterError::UnboundVariable(id.clone()))
+}
+
+fn single_target(pc: StatementIdx, invocation: &cairo_lang_sierra::program::Invocation) -> StatementIdx {
+    fallthrough_target(pc, &invocation.branches[0])
+}
+
+fn fallthrough_target(
+    pc: StatementIdx,
+    branch: &cairo_lang_sierra::program::BranchInfo,
+) -> StatementIdx {
+    match branch.target {
+        BranchTarget::Fallthrough => pc.next(),
+        BranchTarget::Statement(next) => next,
+    }
+}
+
+/// `dup<T>` has one input and two outputs: both outputs are bound to a clone of the single input
+/// (unlike the general passthrough case, a single zip of results with inputs would only bind the
+/// first output and leave the second `UnboundVariable`).
+fn exec_dup(
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let value = inputs.pop().context("dup libfunc expects exactly one input")?;
+    let branch = &invocation.branches[0];
+    for id in &branch.results {
+        vars.insert(id.clone(), value.clone());
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+fn exec_enum(
+    libfunc: &EnumConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    match libfunc {
+        EnumConcreteLibfunc::Init(init) => {
+            let value = inputs.pop().context("enum_init expects exactly one input")?;
+            let branch = &invocation.branches[0];
+            vars.insert(
+                branch.results[0].clone(),
+                Value::Enum { variant: init.index, value: Box::new(value) },
+            );
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+        EnumConcreteLibfunc::Match(_) => {
+            let matched = inputs.pop().context("enum match expects a single enum input")?;
+            let (variant, inner) = match matched {
+                Value::Enum { variant, value } => (variant, *value),
+                other => bail!("enum match applied to a non-enum value: {other:?}"),
+            };
+            let branch = invocation
+                .branches
+                .get(variant)
+                .with_context(|| format!("enum variant {variant} has no matching branch"))?;
+            vars.insert(branch.results[0].clone(), inner);
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+    }
+}
+
+fn exec_struct(
+    libfunc: &StructConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let branch = &invocation.branches[0];
+    match libfunc {
+        StructConcreteLibfunc::Deconstruct(_) => {
+            let members = match inputs.into_iter().next() {
+                Some(Value::Struct(members)) => members,
+                other => bail!("struct_deconstruct applied to a non-struct value: {other:?}"),
+            };
+            for (id, value) in branch.results.iter().zip(members) {
+                vars.insert(id.clone(), value);
+            }
+        }
+        StructConcreteLibfunc::Construct(_) => {
+            vars.insert(branch.results[0].clone(), Value::Struct(inputs));
+        }
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+fn exec_array(
+    _libfunc: &cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let branch = &invocation.branches[0];
+    if inputs.is_empty() {
+        vars.insert(branch.results[0].clone(), Value::Array(Vec::new()));
+    } else {
+        let element = inputs.pop().unwrap();
+        let mut array = match inputs.pop() {
+            Some(Value::Array(array)) => array,
+            other => bail!("array_append applied to a non-array value: {other:?}"),
+        };
+        array.push(element);
+        vars.insert(branch.results[0].clone(), Value::Array(array));
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+fn exec_box(
+    _libfunc: &SignatureOnlyConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let branch = &invocation.branches[0];
+    let value = inputs.pop().context("box libfunc expects exactly one input")?;
+    let boxed = match value {
+        Value::Box(inner) => *inner,
+        other => Value::Box(Box::new(other)),
+    };
+    vars.insert(branch.results[0].clone(), boxed);
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+fn exec_felt252(
+    libfunc: &cairo_lang_sierra::extensions::felt252::Felt252Concrete,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    use cairo_lang_sierra::extensions::felt252::{Felt252BinaryOperator, Felt252Concrete};
+    let branch = &invocation.branches[0];
+    let as_felt = |value: &Value| match value {
+        Value::Felt252(felt) => Ok(felt.clone()),
+        other => Err(anyhow::anyhow!("expected a felt252 value, found {other:?}")),
+    };
+    match libfunc {
+        Felt252Concrete::BinaryOperation(op) => {
+            let lhs = as_felt(&inputs[0])?;
+            let rhs = as_felt(&inputs[1])?;
+            let result = match op.operator {
+                Felt252BinaryOperator::Add => lhs + rhs,
+                Felt252BinaryOperator::Sub => lhs - rhs,
+                Felt252BinaryOperator::Mul => lhs * rhs,
+                Felt252BinaryOperator::Div => bail!("felt252_div is not supported by --emulate"),
+            };
+            vars.insert(branch.results[0].clone(), Value::Felt252(result));
+        }
+        Felt252Concrete::Const(konst) => {
+            vars.insert(branch.results[0].clone(), Value::Felt252(konst.c.clone()));
+        }
+        Felt252Concrete::IsZero(_) => {
+            let value = as_felt(&inputs[0])?;
+            let taken_branch =
+                if value == BigInt::from(0) { &invocation.branches[0] } else { &invocation.branches[1] };
+            return Ok(StepOutcome::Next(fallthrough_target(pc, taken_branch)));
+        }
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+/// Executes the `u8`/`u16`/`u32`/`u64`/`u128` integer libfuncs: constants, equality, is-zero,
+/// felt252 conversions, and `overflowing_{add,sub}`, which take the success branch (0) on no
+/// overflow and the overflow branch (1) otherwise, both re-binding the wrapped result.
+macro_rules! impl_uint_exec {
+    ($fn_name:ident, $concrete:ty, $value_variant:ident, $native:ty) => {
+        fn $fn_name(
+            libfunc: &$concrete,
+            invocation: &cairo_lang_sierra::program::Invocation,
+            inputs: Vec<Value>,
+            vars: &mut HashMap<VarId, Value>,
+            pc: StatementIdx,
+        ) -> anyhow::Result<StepOutcome> {
+            let as_native = |value: &Value| match value {
+                Value::$value_variant(v) => Ok(*v),
+                other => Err(anyhow::anyhow!(
+                    concat!("expected a ", stringify!($value_variant), " value, found {:?}"),
+                    other
+                )),
+            };
+            let branch = &invocation.branches[0];
+            match libfunc {
+                IntConcrete::Const(konst) => {
+                    vars.insert(branch.results[0].clone(), Value::$value_variant(konst.c as $native));
+                    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+                }
+                IntConcrete::Operation(op) => {
+                    let lhs = as_native(&inputs[0])?;
+                    let rhs = as_native(&inputs[1])?;
+                    let (result, overflowed) = match op.operator {
+                        IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
+                        IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
+                    };
+                    let taken_branch =
+                        if overflowed { &invocation.branches[1] } else { &invocation.branches[0] };
+                    vars.insert(taken_branch.results[0].clone(), Value::$value_variant(result));
+                    Ok(StepOutcome::Next(fallthrough_target(pc, taken_branch)))
+                }
+                IntConcrete::Equal(_) => {
+                    let lhs = as_native(&inputs[0])?;
+                    let rhs = as_native(&inputs[1])?;
+                    let taken_branch =
+                        if lhs == rhs { &invocation.branches[0] } else { &invocation.branches[1] };
+                    Ok(StepOutcome::Next(fallthrough_target(pc, taken_branch)))
+                }
+                IntConcrete::IsZero(_) => {
+                    let value = as_native(&inputs[0])?;
+                    let taken_branch = if value == 0 as $native {
+                        &invocation.branches[0]
+                    } else {
+                        &invocation.branches[1]
+                    };
+                    Ok(StepOutcome::Next(fallthrough_target(pc, taken_branch)))
+                }
+                IntConcrete::ToFelt252(_) => {
+                    let value = as_native(&inputs[0])?;
+                    vars.insert(branch.results[0].clone(), Value::Felt252(BigInt::from(value)));
+                    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+                }
+                IntConcrete::FromFelt252(_) => {
+                    let felt = match &inputs[0] {
+                        Value::Felt252(felt) => felt,
+                        other => bail!("expected a felt252 value, found {other:?}"),
+                    };
+                    match felt.to_u128().filter(|v| *v <= <$native>::MAX as u128) {
+                        Some(value) => {
+                            vars.insert(branch.results[0].clone(), Value::$value_variant(value as $native));
+                            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+                        }
+                        None => {
+                            let overflow_branch = &invocation.branches[1];
+                            Ok(StepOutcome::Next(fallthrough_target(pc, overflow_branch)))
+                        }
+                    }
+                }
+                _ => Err(InterpreterError::UnsupportedLibfunc {
+                    libfunc_id: invocation.libfunc_id.to_string(),
+                    statement_idx: pc,
+                }
+                .into()),
+            }
+        }
+    };
+}
+
+impl_uint_exec!(exec_uint8, Uint8Concrete, Uint8, u8);
+impl_uint_exec!(exec_uint16, Uint16Concrete, Uint16, u16);
+impl_uint_exec!(exec_uint32, Uint32Concrete, Uint32, u32);
+impl_uint_exec!(exec_uint64, Uint64Concrete, Uint64, u64);
+impl_uint_exec!(exec_uint128, Uint128Concrete, Uint128, u128);
+
+/// Executes gas-builtin bookkeeping libfuncs.
+///
+/// The interpreter doesn't model the real per-libfunc cost table that `calc_ap_changes`'s sibling
+/// gas model is built from - `run`'s coarse per-statement decrement already stands in for overall
+/// gas accounting, so every withdrawal here is treated as requesting a single unit, just enough to
+/// exercise `--available-gas` programs' success/failure branches.
+fn exec_gas(
+    libfunc: &GasConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let as_gas = |value: &Value| match value {
+        Value::GasBuiltin(amount) => Ok(*amount),
+        other => Err(anyhow::anyhow!("expected a GasBuiltin value, found {other:?}")),
+    };
+    match libfunc {
+        GasConcreteLibfunc::WithdrawGas(_) | GasConcreteLibfunc::BuiltinWithdrawGas(_) => {
+            let amount = as_gas(&inputs[0])?;
+            if amount >= 1 {
+                let branch = &invocation.branches[0];
+                vars.insert(branch.results[0].clone(), Value::GasBuiltin(amount - 1));
+                Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+            } else {
+                let branch = &invocation.branches[1];
+                vars.insert(branch.results[0].clone(), Value::GasBuiltin(amount));
+                Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+            }
+        }
+        GasConcreteLibfunc::RedepositGas(_) => {
+            let amount = as_gas(&inputs[0])?;
+            let branch = &invocation.branches[0];
+            vars.insert(branch.results[0].clone(), Value::GasBuiltin(amount));
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+        GasConcreteLibfunc::GetAvailableGas(_) => {
+            let amount = as_gas(&inputs[0])?;
+            let branch = &invocation.branches[0];
+            vars.insert(branch.results[0].clone(), Value::GasBuiltin(amount));
+            vars.insert(branch.results[1].clone(), Value::Felt252(BigInt::from(amount)));
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+        _ => Err(InterpreterError::UnsupportedLibfunc {
+            libfunc_id: invocation.libfunc_id.to_string(),
+            statement_idx: pc,
+        }
+        .into()),
+    }
+}
+
+fn exec_nullable(
+    libfunc: &NullableConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    match libfunc {
+        NullableConcreteLibfunc::Null(_) => {
+            let branch = &invocation.branches[0];
+            vars.insert(branch.results[0].clone(), Value::Nullable(None));
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+        NullableConcreteLibfunc::NullableFromBox(_) => {
+            let value = inputs.pop().context("nullable_from_box expects exactly one input")?;
+            let branch = &invocation.branches[0];
+            vars.insert(branch.results[0].clone(), Value::Nullable(Some(Box::new(value))));
+            Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+        }
+        NullableConcreteLibfunc::MatchNullable(_) => {
+            let value = inputs.pop().context("match_nullable expects exactly one input")?;
+            match value {
+                Value::Nullable(None) => {
+                    let branch = &invocation.branches[0];
+                    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+                }
+                Value::Nullable(Some(inner)) => {
+                    let branch = &invocation.branches[1];
+                    vars.insert(branch.results[0].clone(), *inner);
+                    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+                }
+                other => bail!("match_nullable applied to a non-nullable value: {other:?}"),
+            }
+        }
+        _ => Err(InterpreterError::UnsupportedLibfunc {
+            libfunc_id: invocation.libfunc_id.to_string(),
+            statement_idx: pc,
+        }
+        .into()),
+    }
+}
+
+fn exec_felt252_dict(
+    libfunc: &Felt252DictConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let branch = &invocation.branches[0];
+    match libfunc {
+        Felt252DictConcreteLibfunc::New(_) => {
+            vars.insert(branch.results[0].clone(), Value::Felt252Dict(HashMap::new()));
+        }
+        Felt252DictConcreteLibfunc::Squash(_) => {
+            let dict =
+                inputs.into_iter().next().context("felt252_dict_squash expects exactly one input")?;
+            vars.insert(branch.results[0].clone(), dict);
+        }
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+/// A dict entry "handle" is represented as the dict it was taken from plus the key it was looked
+/// up under - carried together as a `Value::Struct` - so that `entry_finalize` can write the new
+/// value back to the right slot.
+fn exec_felt252_dict_entry(
+    libfunc: &Felt252DictEntryConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let branch = &invocation.branches[0];
+    match libfunc {
+        Felt252DictEntryConcreteLibfunc::Get(_) => {
+            let key = inputs.pop().context("felt252_dict_entry_get expects a key")?;
+            let dict = inputs.pop().context("felt252_dict_entry_get expects a dict")?;
+            let (dict, key) = match (dict, key) {
+                (Value::Felt252Dict(dict), Value::Felt252(key)) => (dict, key),
+                (other, _) => bail!("felt252_dict_entry_get applied to a non-dict value: {other:?}"),
+            };
+            let current = dict.get(&key).cloned().unwrap_or(Value::Felt252(BigInt::from(0)));
+            vars.insert(branch.results[0].clone(), current);
+            vars.insert(
+                branch.results[1].clone(),
+                Value::Struct(vec![Value::Felt252Dict(dict), Value::Felt252(key)]),
+            );
+        }
+        Felt252DictEntryConcreteLibfunc::Finalize(_) => {
+            let new_value = inputs.pop().context("felt252_dict_entry_finalize expects a new value")?;
+            let entry = inputs.pop().context("felt252_dict_entry_finalize expects an entry")?;
+            let mut members = match entry {
+                Value::Struct(members) if members.len() == 2 => members,
+                other => bail!("felt252_dict_entry_finalize applied to a malformed entry: {other:?}"),
+            };
+            let key = match members.pop().unwrap() {
+                Value::Felt252(key) => key,
+                other => bail!("felt252_dict_entry_finalize entry has a non-felt252 key: {other:?}"),
+            };
+            let mut dict = match members.pop().unwrap() {
+                Value::Felt252Dict(dict) => dict,
+                other => bail!("felt252_dict_entry_finalize entry wraps a non-dict value: {other:?}"),
+            };
+            dict.insert(key, new_value);
+            vars.insert(branch.results[0].clone(), Value::Felt252Dict(dict));
+        }
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
+
+/// `store_temp`/`rename`/`store_local`/`alloc_local`/`finalize_locals` are the memory-management
+/// boilerplate the real Sierra generator inserts into essentially every statement - the
+/// interpreter has no notion of separate temp/local memory segments, so `store_temp`/`rename`/
+/// `store_local` are handled as a pure relabeling of their input, and the other two as below.
+fn exec_mem(
+    libfunc: &MemConcreteLibfunc,
+    invocation: &cairo_lang_sierra::program::Invocation,
+    mut inputs: Vec<Value>,
+    vars: &mut HashMap<VarId, Value>,
+    pc: StatementIdx,
+) -> anyhow::Result<StepOutcome> {
+    let branch = &invocation.branches[0];
+    match libfunc {
+        MemConcreteLibfunc::StoreTemp(_) | MemConcreteLibfunc::Rename(_) | MemConcreteLibfunc::StoreLocal(_) => {
+            let value = inputs.pop().context("store/rename libfunc expects exactly one input")?;
+            vars.insert(branch.results[0].clone(), value);
+        }
+        // `alloc_local` reserves a local-variable slot ahead of the `store_local` that will fill
+        // it; the interpreter has no separate memory to reserve, so it binds a placeholder that
+        // gets immediately overwritten.
+        MemConcreteLibfunc::AllocLocal(_) => {
+            vars.insert(branch.results[0].clone(), Value::Felt252(BigInt::from(0)));
+        }
+        // Marks the end of a function's local-slot allocations; it carries no values of its own.
+        MemConcreteLibfunc::FinalizeLocals(_) => {}
+    }
+    Ok(StepOutcome::Next(fallthrough_target(pc, branch)))
+}
@@ -0,0 +1,211 @@
+//! Compiles and runs a Cairo program: `cairo-run run`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Ok};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::{check_compiler_path, setup_project};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
+use cairo_lang_starknet::contract::get_contracts_info;
+use clap::Parser;
+
+use crate::ap_report::ApReportFormat;
+use crate::emit::EmitSpec;
+use crate::{ap_report, args, backtrace, emit, interpreter, verify, vm_exec};
+
+/// Arguments for `cairo-run run`.
+#[derive(Parser, Debug)]
+pub struct RunArgs {
+    /// The file to compile and run.
+    path: PathBuf,
+    /// Whether path is a single file.
+    #[arg(short, long)]
+    single_file: bool,
+    /// In cases where gas is available, the amount of provided gas.
+    #[arg(long)]
+    available_gas: Option<usize>,
+    /// Whether to print the memory.
+    #[arg(long, default_value_t = false)]
+    print_full_memory: bool,
+    /// Interprets the compiled Sierra program directly instead of lowering it to CASM.
+    /// Useful for diagnosing codegen bugs and on platforms where CASM execution is unavailable.
+    #[arg(long, default_value_t = false)]
+    emulate: bool,
+    /// On panic, print a source-mapped backtrace after the raw panic payload.
+    #[arg(long, default_value_t = false)]
+    backtrace: bool,
+    /// Emits an artifact to a path. May be given multiple times. KIND is one of: sierra,
+    /// sierra-no-debug, casm, casm-bytecode, debug-info.
+    #[arg(long = "emit", value_name = "KIND=PATH")]
+    emit: Vec<EmitSpec>,
+    /// The function to run, e.g. `my_crate::my_contract::foo`. Defaults to `::main`.
+    #[arg(long, default_value = "::main")]
+    function: String,
+    /// For projects declaring more than one contract, requires the function `--function` resolves
+    /// to (its first suffix match) to belong to this contract, and errors otherwise; other
+    /// matching functions in other contracts are not considered.
+    #[arg(long)]
+    contract_path: Option<String>,
+    /// A felt252 literal (decimal or `0x`-prefixed hex) or short string (e.g. `'hello'`) to pass
+    /// as an argument to the entry function. May be given multiple times, in parameter order.
+    #[arg(long = "args")]
+    args: Vec<String>,
+    /// Prints a per-function ap-usage report computed from `calc_ap_changes`, as a table by
+    /// default or as JSON with `--ap-report=json`.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "table")]
+    ap_report: Option<ApReportFormat>,
+    /// Cross-validates `calc_ap_changes`'s predictions against the `ap` deltas actually observed
+    /// while running the program, reporting any statement where they disagree. Only supported
+    /// for the CASM backend, which is the one that exposes a real `ap` register.
+    #[arg(long, default_value_t = false)]
+    verify_ap_model: bool,
+}
+
+/// Runs `cairo-run run`.
+pub fn run(args: RunArgs) -> anyhow::Result<()> {
+    // Check if args.path is a file or a directory.
+    check_compiler_path(args.single_file, &args.path)?;
+
+    let db = &mut RootDatabase::builder().detect_corelib().build()?;
+
+    let main_crate_ids = setup_project(db, Path::new(&args.path))?;
+
+    if DiagnosticsReporter::stderr().check(db) {
+        anyhow::bail!("failed to compile: {}", args.path.display());
+    }
+
+    let sierra_program = db
+        .get_sierra_program(main_crate_ids.clone())
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let replacer = DebugReplacer { db };
+    if args.available_gas.is_none() && sierra_program.requires_gas_counter() {
+        anyhow::bail!("Program requires gas counter, please provide `--available-gas` argument.");
+    }
+
+    if let Some(format) = args.ap_report {
+        ap_report::print(&replacer.apply(&sierra_program), format)?;
+    }
+
+    if args.emulate && args.verify_ap_model {
+        anyhow::bail!("--verify-ap-model is only supported with the CASM backend, not --emulate.");
+    }
+    if args.emulate && args.backtrace {
+        anyhow::bail!("--backtrace is only supported with the CASM backend, not --emulate.");
+    }
+
+    if args.emulate {
+        let program = replacer.apply(&sierra_program);
+        let registry = cairo_lang_sierra::program_registry::ProgramRegistry::new(&program)
+            .with_context(|| "Failed setting up the program registry.")?;
+        let function = program
+            .funcs
+            .iter()
+            .find(|f| f.id.to_string().ends_with(&args.function))
+            .with_context(|| format!("Could not find function `{}`.", args.function))?;
+        let function_args = args::parse_and_validate(&args.args, function)?
+            .iter()
+            .map(|felt| interpreter::Value::Felt252(args::to_bigint(felt)))
+            .collect();
+        let result =
+            interpreter::run(&program, &registry, function.entry_point, function_args, args.available_gas)
+                .with_context(|| "Failed to run the function.")?;
+        match result.value {
+            interpreter::InterpreterResultValue::Success(values) => {
+                println!("Run completed successfully, returning {values:?}")
+            }
+            interpreter::InterpreterResultValue::Panic(values) => {
+                println!("Run panicked with {values:?}.")
+            }
+        }
+        if let Some(gas) = result.gas_counter {
+            println!("Remaining gas: {gas}");
+        }
+        return Ok(());
+    }
+
+    let contracts_info = get_contracts_info(db, main_crate_ids, &replacer)?;
+
+    let runner = SierraCasmRunner::new(
+        replacer.apply(&sierra_program),
+        if args.available_gas.is_some() { Some(Default::default()) } else { None },
+        contracts_info.clone(),
+    )
+    .with_context(|| "Failed setting up runner.")?;
+
+    emit::write_artifacts(
+        &args.emit,
+        &replacer.apply(&sierra_program),
+        sierra_program.as_ref(),
+        &runner,
+    )?;
+
+    let function = runner.find_function(&args.function)?;
+    if let Some(contract_path) = &args.contract_path {
+        // Disambiguate by actually consulting `contracts_info` (keyed by each contract's entry
+        // point function ids) rather than a substring check on the resolved function's id, which
+        // can match the wrong contract entirely whenever one contract's module path happens to be
+        // a substring of another's.
+        let module_path = function.id.to_string().rsplit_once("::").map_or("", |(module, _)| module);
+        let belongs_to_contract = contracts_info.get(&function.id).is_some()
+            && module_path.split("::").eq(contract_path.split("::"));
+        if !belongs_to_contract {
+            anyhow::bail!(
+                "function `{}` does not belong to contract `{contract_path}`",
+                function.id
+            );
+        }
+    }
+    let function_args = args::parse_and_validate(&args.args, function)?;
+
+    let result = vm_exec::run_with_trace(&runner, function, &function_args, args.available_gas)?;
+    match result.value {
+        cairo_lang_runner::RunResultValue::Success(values) => {
+            println!("Run completed successfully, returning {values:?}")
+        }
+        cairo_lang_runner::RunResultValue::Panic(values) => {
+            print!("Run panicked with [");
+            for value in &values {
+                match as_cairo_short_string(value) {
+                    Some(as_string) => print!("{value} ('{as_string}'), "),
+                    None => print!("{value}, "),
+                }
+            }
+            println!("].");
+            if args.backtrace {
+                let frames = backtrace::reconstruct(
+                    &runner,
+                    &replacer.apply(&sierra_program),
+                    &result.memory,
+                    &result.trace,
+                )
+                .with_context(|| "Failed to reconstruct the panic backtrace.")?;
+                backtrace::print(&frames);
+            }
+        }
+    }
+    if let Some(gas) = result.gas_counter {
+        println!("Remaining gas: {gas}");
+    }
+    if args.print_full_memory {
+        print!("Full memory: [");
+        for cell in &result.memory {
+            match cell {
+                None => print!("_, "),
+                Some(value) => print!("{value}, "),
+            }
+        }
+        println!("]");
+    }
+    if args.verify_ap_model {
+        let mismatches =
+            verify::cross_validate(&replacer.apply(&sierra_program), &runner, &result.trace)?;
+        verify::print(&mismatches);
+    }
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Cross-validates `calc_ap_changes`'s predictions against the `ap` deltas actually observed
+//! while running a program, to catch model drift in `generate_equations`/`core_libfunc_ap_change`.
+//! Selected with `--verify-ap-model`.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use cairo_lang_casm::debug_info::StatementDebugInfo;
+use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_lang_sierra_ap_change::calc_ap_changes;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+
+/// A single statement where the predicted and observed `ap` deltas disagree.
+pub struct Mismatch {
+    pub statement_idx: StatementIdx,
+    /// `calc_ap_changes`'s resolved delta for this statement, or `None` if the statement never
+    /// made it into the solved equations (e.g. unreachable code).
+    pub predicted: Option<usize>,
+    pub observed: usize,
+}
+
+/// Maps a relocated trace `pc` to the `StatementIdx` whose code starts at or before it. Mirrors
+/// `backtrace::statement_at_offset`; duplicated to keep each module's debug-info usage
+/// self-contained.
+///
+/// `pc` is a relocated address (cairo-vm reserves address 0, so the program segment is relocated
+/// starting at 1), while `code_offset` is a plain 0-indexed bytecode offset - subtract the
+/// program's base address before comparing them.
+fn statement_at_offset(
+    sierra_statement_info: &[StatementDebugInfo],
+    pc: usize,
+) -> Option<StatementIdx> {
+    let offset = pc.checked_sub(1)?;
+    sierra_statement_info
+        .iter()
+        .enumerate()
+        .filter(|(_, info)| info.code_offset <= offset)
+        .max_by_key(|(_, info)| info.code_offset)
+        .map(|(idx, _)| StatementIdx(idx))
+}
+
+/// Compares `calc_ap_changes(program)`'s per-statement predictions against the `ap` deltas
+/// observed in `trace` - the relocated `(pc, ap, fp)` samples recorded while actually running the
+/// program - and returns every statement where they disagree.
+pub fn cross_validate(
+    program: &Program,
+    runner: &SierraCasmRunner,
+    trace: &[RelocatedTraceEntry],
+) -> anyhow::Result<Vec<Mismatch>> {
+    let info = calc_ap_changes(program).with_context(|| "Failed computing the ap-change model.")?;
+    let debug_info = &runner.get_casm_program().debug_info.sierra_statement_info;
+
+    // Only the delta observed the first time a statement executes is recorded: statements inside
+    // loops should see the same `ap` delta on every iteration when the model is correct, so later
+    // iterations are redundant (and, if they differ, are themselves evidence of non-determinism
+    // the model can't represent anyway).
+    //
+    // A statement can lower to more than one CASM instruction, so its total `ap` delta is the gap
+    // between the `ap` at its first instruction and the `ap` at the next statement's first
+    // instruction - not just the delta of its last instruction, which would silently drop every
+    // intra-statement instruction's contribution.
+    let mut observed: HashMap<StatementIdx, usize> = HashMap::new();
+    let mut current: Option<(StatementIdx, usize)> = None;
+    for entry in trace {
+        let Some(statement_idx) = statement_at_offset(debug_info, entry.pc) else { continue };
+        match current {
+            Some((prev_idx, _)) if prev_idx == statement_idx => {}
+            Some((prev_idx, start_ap)) => {
+                observed.entry(prev_idx).or_insert(entry.ap - start_ap);
+                current = Some((statement_idx, entry.ap));
+            }
+            None => current = Some((statement_idx, entry.ap)),
+        }
+    }
+
+    let mut mismatches: Vec<Mismatch> = observed
+        .into_iter()
+        .filter_map(|(statement_idx, delta)| {
+            let predicted = info.variable_values.get(&statement_idx).copied();
+            (predicted != Some(delta)).then_some(Mismatch { statement_idx, predicted, observed: delta })
+        })
+        .collect();
+    mismatches.sort_by_key(|m| m.statement_idx.0);
+    Ok(mismatches)
+}
+
+/// Prints every mismatch found by [`cross_validate`].
+pub fn print(mismatches: &[Mismatch]) {
+    if mismatches.is_empty() {
+        println!("ap-change model matches observed execution.");
+        return;
+    }
+    println!("ap-change model mismatches:");
+    for mismatch in mismatches {
+        let predicted =
+            mismatch.predicted.map(|v| v.to_string()).unwrap_or_else(|| "unknown".to_string());
+        println!(
+            "  {:?}: predicted {predicted}, observed {}",
+            mismatch.statement_idx, mismatch.observed
+        );
+    }
+}
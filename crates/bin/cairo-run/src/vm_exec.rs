@@ -0,0 +1,39 @@
+//! Drives a function's CASM directly through `cairo_lang_runner`'s lower-level VM entry point,
+//! instead of `SierraCasmRunner::run_function_with_starknet_context`'s summarized `RunResult`.
+//!
+//! The summarized result only carries the final memory and outcome, not the instruction-level
+//! `(pc, ap, fp)` trace - `--backtrace` and `--verify-ap-model` both need that trace, so they run
+//! through here instead.
+
+use anyhow::Context;
+use cairo_felt::Felt252;
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner, StarknetState};
+use cairo_lang_sierra::program::Function;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+
+/// The same summary `run_function_with_starknet_context` would have returned, plus the full
+/// relocated execution trace.
+pub struct TracedRunResult {
+    pub value: RunResultValue,
+    pub gas_counter: Option<usize>,
+    pub memory: Vec<Option<Felt252>>,
+    pub trace: Vec<RelocatedTraceEntry>,
+}
+
+/// Runs `function` with `args`, recording the VM's full `(pc, ap, fp)` trace as it executes.
+pub fn run_with_trace(
+    runner: &SierraCasmRunner,
+    function: &Function,
+    args: &[Felt252],
+    available_gas: Option<usize>,
+) -> anyhow::Result<TracedRunResult> {
+    let (result, vm) = runner
+        .run_function_with_vm(function, args, available_gas, StarknetState::default())
+        .with_context(|| "Failed to run the function.")?;
+    let trace = vm
+        .get_relocated_trace()
+        .with_context(|| "run did not produce a trace; tracing must be enabled for --backtrace \
+                           and --verify-ap-model")?
+        .clone();
+    Ok(TracedRunResult { value: result.value, gas_counter: result.gas_counter, memory: result.memory, trace })
+}
@@ -0,0 +1,65 @@
+//! Per-function AP-usage reporting, built from [`calc_ap_changes`](crate::calc_ap_changes)'s
+//! output so callers can see which functions dominate stack/memory usage without manually reading
+//! `sierra_statement_info`.
+
+use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::{GenStatement, Program};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+
+use crate::ap_change_info::ApChangeInfo;
+use crate::core_libfunc_ap_change;
+use crate::{ApChange, ApChangeError};
+
+/// A per-function summary of the `ap` usage `calc_ap_changes` predicted for it.
+pub struct FunctionApReport {
+    pub function_id: FunctionId,
+    /// The function's total known `ap` growth per call, if one was resolved.
+    pub ap_change: Option<usize>,
+    /// The size locals finalization allocates for this function's frame.
+    pub locals_size: usize,
+    /// Whether any branch within the function resolved to `ApChange::Unknown`.
+    pub has_unknown_branch: bool,
+}
+
+/// Builds one [`FunctionApReport`] per function in `program`, from `info` (as returned by
+/// `calc_ap_changes`).
+pub fn build_reports(
+    program: &Program,
+    info: &ApChangeInfo,
+) -> Result<Vec<FunctionApReport>, ApChangeError> {
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
+    let mut entry_points: Vec<usize> = program.funcs.iter().map(|f| f.entry_point.0).collect();
+    entry_points.sort_unstable();
+
+    program
+        .funcs
+        .iter()
+        .map(|func| {
+            let end = entry_points
+                .iter()
+                .find(|&&idx| idx > func.entry_point.0)
+                .copied()
+                .unwrap_or(program.statements.len());
+            let mut locals_size = 0;
+            let mut has_unknown_branch = false;
+            for statement in &program.statements[func.entry_point.0..end] {
+                let GenStatement::Invocation(invocation) = statement else { continue };
+                let libfunc = registry.get_libfunc(&invocation.libfunc_id)?;
+                for ap_change in core_libfunc_ap_change::core_libfunc_ap_change(libfunc, &registry) {
+                    match ap_change {
+                        ApChange::Unknown => has_unknown_branch = true,
+                        ApChange::AtLocalsFinalization(size) => locals_size += size,
+                        _ => {}
+                    }
+                }
+            }
+            Ok(FunctionApReport {
+                function_id: func.id.clone(),
+                ap_change: info.function_ap_change.get(&func.id).copied(),
+                locals_size,
+                has_unknown_branch,
+            })
+        })
+        .collect()
+}
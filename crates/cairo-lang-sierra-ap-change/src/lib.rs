@@ -12,6 +12,7 @@ use generate_equations::{Effects, Var};
 use thiserror::Error;
 
 pub mod ap_change_info;
+pub mod ap_report;
 pub mod core_libfunc_ap_change;
 mod generate_equations;
 